@@ -63,6 +63,55 @@ impl Rect {
             0.
         }
     }
+
+    /// Shrinks the rect by `margin` on every side, collapsing to a
+    /// zero-area rect at the same origin when it's too small to hold the
+    /// margin.
+    pub fn inner(&self, margin: f64) -> Rect {
+        if self.w < 2.0 * margin || self.h < 2.0 * margin {
+            Rect::from_points(self.x, self.y, 0.0, 0.0)
+        } else {
+            Rect::from_points(
+                self.x + margin,
+                self.y + margin,
+                self.w - 2.0 * margin,
+                self.h - 2.0 * margin,
+            )
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        self.w * self.h
+    }
+
+    /// Returns true if `(px, py)` lies within the rect, inclusive of its edges.
+    pub fn contains(&self, px: f64, py: f64) -> bool {
+        px >= self.x && px <= self.x + self.w && py >= self.y && py <= self.y + self.h
+    }
+
+    /// Returns the smallest rect that contains both `self` and `other`.
+    pub fn union(&self, other: &Rect) -> Rect {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let w = (self.x + self.w).max(other.x + other.w) - x;
+        let h = (self.y + self.h).max(other.y + other.h) - y;
+        Rect::from_points(x, y, w, h)
+    }
+
+    /// Returns the overlapping area between `self` and `other`, or `None`
+    /// if they don't overlap.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let w = (self.x + self.w).min(other.x + other.w) - x;
+        let h = (self.y + self.h).min(other.y + other.h) - y;
+
+        if w <= 0.0 || h <= 0.0 {
+            None
+        } else {
+            Some(Rect::from_points(x, y, w, h))
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -98,16 +147,172 @@ impl Mappable for MapItem {
     }
 }
 
-pub struct TreemapLayout {}
+/// A node in a hierarchical treemap.
+///
+/// A `TreemapNode` is either a leaf with a fixed `size`, or a branch whose
+/// `size` is the sum of its `children`'s sizes. Laying out a tree with
+/// [`TreemapLayout::layout_tree`] assigns `bounds` to the node itself and,
+/// recursively, to every descendant.
+#[derive(Clone)]
+pub struct TreemapNode {
+    size: f64,
+    bounds: Rect,
+    children: Option<Vec<TreemapNode>>,
+}
+
+impl TreemapNode {
+    /// Creates a leaf node with the given size and no children.
+    pub fn new(size: f64) -> TreemapNode {
+        TreemapNode {
+            size: size,
+            bounds: Rect::new(),
+            children: None,
+        }
+    }
+
+    /// Creates a branch node whose size is the sum of `children`'s sizes.
+    pub fn with_children(children: Vec<TreemapNode>) -> TreemapNode {
+        let size = children.iter().map(|c| c.size()).sum();
+        TreemapNode {
+            size: size,
+            bounds: Rect::new(),
+            children: Some(children),
+        }
+    }
+
+    pub fn children(&self) -> Option<&[TreemapNode]> {
+        self.children.as_deref()
+    }
+
+    /// Descends into the deepest child whose bounds contain `(x, y)`,
+    /// falling back to this node if none of its children match.
+    pub fn node_at(&self, x: f64, y: f64) -> Option<&TreemapNode> {
+        if !self.bounds.contains(x, y) {
+            return None;
+        }
+
+        if let Some(children) = &self.children {
+            for child in children {
+                if let Some(found) = child.node_at(x, y) {
+                    return Some(found);
+                }
+            }
+        }
+
+        Some(self)
+    }
+}
+
+impl Mappable for TreemapNode {
+    fn size(&self) -> f64 {
+        self.size
+    }
+
+    fn bounds(&self) -> &Rect {
+        &self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct AspectStats {
+    pub mean: f64,
+    pub max: f64,
+    pub min: f64,
+    pub count: usize,
+}
+
+/// The tessellation strategy used by [`TreemapLayout::layout_items`].
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum LayoutAlgorithm {
+    /// Sorts items by descending size, then fills one row at a time,
+    /// adding items to the row while its aspect ratio does not get worse
+    /// and recursing into the remaining space. Destroys input order.
+    Squarified,
+    /// Strip treemap: preserves input order, grouping items into
+    /// left-to-right strips that stay reasonably square.
+    Strip,
+    /// Splits the bounds along its longer axis, giving each item a slice
+    /// proportional to its size. Simple, but produces thin rectangles.
+    SliceAndDice,
+}
+
+impl Default for LayoutAlgorithm {
+    fn default() -> Self {
+        LayoutAlgorithm::Squarified
+    }
+}
+
+pub struct TreemapLayout {
+    padding: f64,
+    frame: f64,
+    algorithm: LayoutAlgorithm,
+}
 
 impl TreemapLayout {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            padding: 0.0,
+            frame: 0.0,
+            algorithm: LayoutAlgorithm::default(),
+        }
+    }
+
+    /// Creates a layout that uses the given algorithm for `layout_items`.
+    pub fn with_algorithm(algorithm: LayoutAlgorithm) -> Self {
+        Self {
+            padding: 0.0,
+            frame: 0.0,
+            algorithm: algorithm,
+        }
+    }
+
+    /// Sets the gap inserted between sibling rectangles.
+    pub fn with_padding(mut self, padding: f64) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the inset applied to the outer bounds before laying out items.
+    pub fn with_frame(mut self, frame: f64) -> Self {
+        self.frame = frame;
+        self
     }
 
     pub fn layout_items<T: Mappable>(&self, items: &mut [T], bounds: Rect) {
-        sort_descending(items);
-        self.layout_items_at(items, bounds);
+        match self.algorithm {
+            LayoutAlgorithm::Squarified => {
+                sort_descending(items);
+                self.layout_items_at(items, bounds.inner(self.frame));
+            }
+            LayoutAlgorithm::Strip => self.layout_items_ordered(items, bounds),
+            LayoutAlgorithm::SliceAndDice => self.layout_row(items, bounds.inner(self.frame)),
+        }
+    }
+
+    /// Returns the first item whose bounds contain `(x, y)`, after a layout
+    /// has been computed.
+    pub fn item_at<'a, T: Mappable>(&self, items: &'a [T], x: f64, y: f64) -> Option<&'a T> {
+        items.iter().find(|item| item.bounds().contains(x, y))
+    }
+
+    /// Lays out a hierarchical tree of nodes, recursing into each node's
+    /// children after its own bounds have been assigned.
+    pub fn layout_tree(&self, root: &mut TreemapNode, bounds: Rect) {
+        root.set_bounds(bounds);
+
+        if let Some(children) = root.children.as_mut() {
+            if !children.is_empty() {
+                self.layout_items(children, bounds);
+                for child in children.iter_mut() {
+                    let child_bounds = *child.bounds();
+                    self.layout_tree(child, child_bounds);
+                }
+            }
+        }
     }
 
     fn layout_items_at<T: Mappable>(
@@ -126,6 +331,10 @@ impl TreemapLayout {
         let h = bounds.h;
 
         let total = self.total_item_size(&items[0..items.len() - 1]);
+        if total <= 0.0 {
+            self.layout_row(items, bounds);
+            return;
+        }
         let mut mid = 0;
         let a = items[0].size() / total;
         let mut b = a;
@@ -173,6 +382,12 @@ impl TreemapLayout {
     fn layout_row<T: Mappable>(&self, items: &mut [T], bounds: Rect) {
         let is_horizontal = bounds.w > bounds.h;
         let total = self.total_item_size(items);
+        if total <= 0.0 {
+            for item in items.iter_mut() {
+                item.set_bounds(Rect::from_points(bounds.x, bounds.y, 0.0, 0.0));
+            }
+            return;
+        }
         let mut a = 0.0;
 
         for item in items {
@@ -190,7 +405,7 @@ impl TreemapLayout {
                 r.y = bounds.y + bounds.h * a;
                 r.h = bounds.h * b;
             }
-            item.set_bounds(r);
+            item.set_bounds(r.inner(self.padding / 2.0));
             a += b;
         }
     }
@@ -198,6 +413,139 @@ impl TreemapLayout {
     fn total_item_size<T: Mappable>(&self, items: &[T]) -> f64 {
         items.iter().map(|i| i.size()).sum()
     }
+
+    /// Lays out `items` as a strip treemap, preserving the caller's
+    /// ordering instead of sorting by size. Items are grouped into
+    /// horizontal strips left-to-right/top-to-bottom, growing each strip
+    /// greedily as long as doing so does not increase its average aspect
+    /// ratio.
+    pub fn layout_items_ordered<T: Mappable>(&self, items: &mut [T], bounds: Rect) {
+        let bounds = bounds.inner(self.frame);
+        let total = self.total_item_size(items);
+
+        if items.is_empty() || total <= 0.0 {
+            for item in items.iter_mut() {
+                item.set_bounds(Rect::from_points(bounds.x, bounds.y, 0.0, 0.0));
+            }
+            return;
+        }
+
+        let scale = bounds.area() / total;
+        let mut y = bounds.y;
+        let mut start = 0;
+
+        while start < items.len() {
+            let mut end = start + 1;
+            let mut best = self.strip_aspect(items, start, end, scale, bounds.w);
+
+            while end < items.len() {
+                let candidate = self.strip_aspect(items, start, end + 1, scale, bounds.w);
+                if candidate > best {
+                    break;
+                }
+                end += 1;
+                best = candidate;
+            }
+
+            y = self.layout_strip(items, start, end, scale, bounds, y);
+            start = end;
+        }
+    }
+
+    /// Mean aspect ratio of `items[start..end]` if laid out as a single
+    /// strip spanning `width`, given sizes normalized by `scale`.
+    fn strip_aspect<T: Mappable>(
+        &self,
+        items: &[T],
+        start: usize,
+        end: usize,
+        scale: f64,
+        width: f64,
+    ) -> f64 {
+        let strip_size: f64 = items[start..end].iter().map(|i| i.size() * scale).sum();
+        let height = strip_size / width;
+        if height <= 0.0 {
+            return 0.0;
+        }
+
+        let sum: f64 = items[start..end]
+            .iter()
+            .map(|i| {
+                let w = i.size() * scale / height;
+                if w <= 0.0 {
+                    0.0
+                } else {
+                    (w / height).max(height / w)
+                }
+            })
+            .sum();
+
+        sum / (end - start) as f64
+    }
+
+    /// Assigns bounds to `items[start..end]` as a single horizontal strip
+    /// starting at `y`, returning the y-offset of the next strip.
+    fn layout_strip<T: Mappable>(
+        &self,
+        items: &mut [T],
+        start: usize,
+        end: usize,
+        scale: f64,
+        bounds: Rect,
+        y: f64,
+    ) -> f64 {
+        let strip_size: f64 = items[start..end].iter().map(|i| i.size() * scale).sum();
+        let height = if bounds.w > 0.0 {
+            strip_size / bounds.w
+        } else {
+            0.0
+        };
+
+        let mut x = bounds.x;
+        for item in items[start..end].iter_mut() {
+            let w = if height > 0.0 {
+                item.size() * scale / height
+            } else {
+                0.0
+            };
+            let r = Rect::from_points(x, y, w, height);
+            item.set_bounds(r.inner(self.padding / 2.0));
+            x += w;
+        }
+
+        y + height
+    }
+
+    /// Computes aspect-ratio statistics over a computed layout, ignoring
+    /// zero-area cells.
+    pub fn aspect_stats<T: Mappable>(&self, items: &[T]) -> AspectStats {
+        let ratios: Vec<f64> = items
+            .iter()
+            .map(|i| i.bounds().aspect_ratio())
+            .filter(|r| *r > 0.0)
+            .collect();
+
+        if ratios.is_empty() {
+            return AspectStats {
+                mean: 0.0,
+                max: 0.0,
+                min: 0.0,
+                count: 0,
+            };
+        }
+
+        let count = ratios.len();
+        let sum: f64 = ratios.iter().sum();
+        let max = ratios.iter().cloned().fold(f64::MIN, f64::max);
+        let min = ratios.iter().cloned().fold(f64::MAX, f64::min);
+
+        AspectStats {
+            mean: sum / count as f64,
+            max: max,
+            min: min,
+            count: count,
+        }
+    }
 }
 
 fn sort_descending<T: Mappable>(items: &mut [T]) {
@@ -1,6 +1,6 @@
 use treemap;
 
-use treemap::{MapItem, Mappable, Rect, TreemapLayout};
+use treemap::{LayoutAlgorithm, MapItem, Mappable, Rect, TreemapLayout};
 
 #[test]
 fn layout_items() {
@@ -61,3 +61,131 @@ fn layout_items() {
         assert_eq!(expected_output[i].h, item_bounds.h);
     }
 }
+
+#[test]
+fn layout_items_with_padding_and_frame() {
+    let bounds = Rect::from_points(0.0, 0.0, 10.0, 10.0);
+    let mut items: Vec<Box<dyn Mappable>> = vec![
+        Box::new(MapItem::with_size(6.0)),
+        Box::new(MapItem::with_size(4.0)),
+    ];
+
+    let layout = TreemapLayout::new().with_padding(1.0).with_frame(1.0);
+    layout.layout_items(&mut items, bounds);
+
+    let frame_bounds = bounds.inner(1.0);
+    for item in &items {
+        let b = item.bounds();
+        assert!(b.x >= frame_bounds.x);
+        assert!(b.y >= frame_bounds.y);
+        assert!(b.x + b.w <= frame_bounds.x + frame_bounds.w);
+        assert!(b.y + b.h <= frame_bounds.y + frame_bounds.h);
+    }
+
+    // padding shrinks each cell so neighbouring rects no longer touch.
+    let a = items[0].bounds();
+    let b = items[1].bounds();
+    assert!(a.w < frame_bounds.w);
+    assert!(b.w < frame_bounds.w);
+}
+
+#[test]
+fn item_at() {
+    let bounds = Rect::from_points(0.0, 0.0, 10.0, 10.0);
+    let mut items: Vec<Box<dyn Mappable>> = vec![
+        Box::new(MapItem::with_size(6.0)),
+        Box::new(MapItem::with_size(4.0)),
+    ];
+
+    let layout = TreemapLayout::new();
+    layout.layout_items(&mut items, bounds);
+
+    let first_bounds = *items[0].bounds();
+    let found = layout
+        .item_at(&items, first_bounds.x, first_bounds.y)
+        .unwrap();
+    assert_eq!(*found.bounds(), first_bounds);
+
+    assert!(layout.item_at(&items, -1.0, -1.0).is_none());
+}
+
+#[test]
+fn aspect_stats() {
+    let bounds = Rect::from_points(0.0, 0.0, 6.0, 4.0);
+    let mut items: Vec<Box<dyn Mappable>> = vec![
+        Box::new(MapItem::with_size(6.0)),
+        Box::new(MapItem::with_size(6.0)),
+        Box::new(MapItem::with_size(4.0)),
+        Box::new(MapItem::with_size(3.0)),
+        Box::new(MapItem::with_size(2.0)),
+        Box::new(MapItem::with_size(2.0)),
+        Box::new(MapItem::with_size(1.0)),
+    ];
+
+    let layout = TreemapLayout::new();
+    layout.layout_items(&mut items, bounds);
+
+    let stats = layout.aspect_stats(&items);
+    assert_eq!(stats.count, items.len());
+    assert!(stats.min <= stats.mean);
+    assert!(stats.mean <= stats.max);
+}
+
+#[test]
+fn aspect_stats_ignores_zero_area_cells() {
+    let bounds = Rect::from_points(0.0, 0.0, 6.0, 4.0);
+    let mut items: Vec<Box<dyn Mappable>> = vec![
+        Box::new(MapItem::with_size(0.0)),
+        Box::new(MapItem::with_size(4.0)),
+    ];
+
+    let layout = TreemapLayout::new();
+    layout.layout_items(&mut items, bounds);
+
+    let stats = layout.aspect_stats(&items);
+    assert_eq!(stats.count, 1);
+}
+
+#[test]
+fn layout_items_with_slice_and_dice() {
+    let bounds = Rect::from_points(0.0, 0.0, 6.0, 4.0);
+    let mut items: Vec<Box<dyn Mappable>> = vec![
+        Box::new(MapItem::with_size(3.0)),
+        Box::new(MapItem::with_size(1.0)),
+        Box::new(MapItem::with_size(2.0)),
+    ];
+
+    let layout = TreemapLayout::with_algorithm(LayoutAlgorithm::SliceAndDice);
+    layout.layout_items(&mut items, bounds);
+
+    // order is preserved, unlike the squarified default.
+    assert_eq!(items[0].size(), 3.0);
+    assert_eq!(items[1].size(), 1.0);
+    assert_eq!(items[2].size(), 2.0);
+
+    let total_area: f64 = items.iter().map(|i| i.bounds().area()).sum();
+    assert!((total_area - bounds.area()).abs() < 1e-9);
+}
+
+#[test]
+fn layout_items_with_strip_algorithm_matches_layout_items_ordered() {
+    let bounds = Rect::from_points(0.0, 0.0, 6.0, 4.0);
+    let mut a: Vec<Box<dyn Mappable>> = vec![
+        Box::new(MapItem::with_size(2.0)),
+        Box::new(MapItem::with_size(6.0)),
+        Box::new(MapItem::with_size(4.0)),
+    ];
+    let mut b: Vec<Box<dyn Mappable>> = vec![
+        Box::new(MapItem::with_size(2.0)),
+        Box::new(MapItem::with_size(6.0)),
+        Box::new(MapItem::with_size(4.0)),
+    ];
+
+    let layout = TreemapLayout::with_algorithm(LayoutAlgorithm::Strip);
+    layout.layout_items(&mut a, bounds);
+    layout.layout_items_ordered(&mut b, bounds);
+
+    for i in 0..a.len() {
+        assert_eq!(*a[i].bounds(), *b[i].bounds());
+    }
+}
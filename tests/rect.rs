@@ -19,6 +19,39 @@ fn new_rect() {
 fn aspect_ratio() {
     let rect = Rect::new();
     assert_eq!(rect.aspect_ratio(), 1.0);
-    let rect2 = Rect::new_from_points(1.0, 1.0, 1.0, 5.0);
+    let rect2 = Rect::from_points(1.0, 1.0, 1.0, 5.0);
     assert_eq!(rect2.aspect_ratio(), 5.0);
 }
+
+#[test]
+fn area() {
+    let rect = Rect::from_points(0.0, 0.0, 3.0, 4.0);
+    assert_eq!(rect.area(), 12.0);
+}
+
+#[test]
+fn contains() {
+    let rect = Rect::from_points(0.0, 0.0, 2.0, 2.0);
+    assert!(rect.contains(1.0, 1.0));
+    assert!(rect.contains(0.0, 0.0));
+    assert!(rect.contains(2.0, 2.0));
+    assert!(!rect.contains(2.1, 1.0));
+    assert!(!rect.contains(1.0, -0.1));
+}
+
+#[test]
+fn union() {
+    let a = Rect::from_points(0.0, 0.0, 2.0, 2.0);
+    let b = Rect::from_points(1.0, 1.0, 2.0, 2.0);
+    assert_eq!(a.union(&b), Rect::from_points(0.0, 0.0, 3.0, 3.0));
+}
+
+#[test]
+fn intersection() {
+    let a = Rect::from_points(0.0, 0.0, 2.0, 2.0);
+    let b = Rect::from_points(1.0, 1.0, 2.0, 2.0);
+    assert_eq!(a.intersection(&b), Some(Rect::from_points(1.0, 1.0, 1.0, 1.0)));
+
+    let c = Rect::from_points(5.0, 5.0, 1.0, 1.0);
+    assert_eq!(a.intersection(&c), None);
+}
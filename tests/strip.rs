@@ -0,0 +1,42 @@
+use treemap;
+
+use treemap::{MapItem, Mappable, Rect, TreemapLayout};
+
+#[test]
+fn layout_items_ordered_preserves_order() {
+    let bounds = Rect::from_points(0.0, 0.0, 6.0, 4.0);
+    let mut items: Vec<Box<dyn Mappable>> = vec![
+        Box::new(MapItem::with_size(2.0)),
+        Box::new(MapItem::with_size(6.0)),
+        Box::new(MapItem::with_size(1.0)),
+        Box::new(MapItem::with_size(4.0)),
+    ];
+
+    let layout = TreemapLayout::new();
+    layout.layout_items_ordered(&mut items, bounds);
+
+    // order is untouched, unlike layout_items which sorts descending.
+    assert_eq!(items[0].size(), 2.0);
+    assert_eq!(items[1].size(), 6.0);
+    assert_eq!(items[2].size(), 1.0);
+    assert_eq!(items[3].size(), 4.0);
+
+    let total_area: f64 = items.iter().map(|i| i.bounds().area()).sum();
+    assert!((total_area - bounds.area()).abs() < 1e-9);
+}
+
+#[test]
+fn layout_items_ordered_zero_size() {
+    let bounds = Rect::from_points(0.0, 0.0, 4.0, 4.0);
+    let mut items: Vec<Box<dyn Mappable>> = vec![
+        Box::new(MapItem::with_size(0.0)),
+        Box::new(MapItem::with_size(0.0)),
+    ];
+
+    let layout = TreemapLayout::new();
+    layout.layout_items_ordered(&mut items, bounds);
+
+    for item in &items {
+        assert_eq!(item.bounds().w, 0.0);
+    }
+}
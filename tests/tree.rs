@@ -0,0 +1,76 @@
+use treemap;
+
+use treemap::{Mappable, Rect, TreemapLayout, TreemapNode};
+
+#[test]
+fn layout_tree() {
+    let bounds = Rect::from_points(0.0, 0.0, 4.0, 4.0);
+
+    let mut root = TreemapNode::with_children(vec![
+        TreemapNode::with_children(vec![TreemapNode::new(3.0), TreemapNode::new(1.0)]),
+        TreemapNode::new(4.0),
+    ]);
+
+    let layout = TreemapLayout::new();
+    layout.layout_tree(&mut root, bounds);
+
+    assert_eq!(*root.bounds(), bounds);
+
+    let children = root.children().unwrap();
+    assert_eq!(children.len(), 2);
+
+    let total_area: f64 = children.iter().map(|c| c.bounds().w * c.bounds().h).sum();
+    assert!((total_area - bounds.w * bounds.h).abs() < 1e-9);
+
+    let nested = &children
+        .iter()
+        .find(|c| c.children().is_some())
+        .unwrap()
+        .children()
+        .unwrap();
+    assert_eq!(nested.len(), 2);
+    for grandchild in nested.iter() {
+        assert!(grandchild.bounds().w > 0.0 && grandchild.bounds().h > 0.0);
+    }
+}
+
+#[test]
+fn node_at() {
+    let bounds = Rect::from_points(0.0, 0.0, 4.0, 4.0);
+
+    let mut root = TreemapNode::with_children(vec![
+        TreemapNode::with_children(vec![TreemapNode::new(3.0), TreemapNode::new(1.0)]),
+        TreemapNode::new(4.0),
+    ]);
+
+    let layout = TreemapLayout::new();
+    layout.layout_tree(&mut root, bounds);
+
+    let grandchild_bounds = *root.children().unwrap()[0].children().unwrap()[0].bounds();
+    let found = root
+        .node_at(grandchild_bounds.x, grandchild_bounds.y)
+        .unwrap();
+    assert!(found.children().is_none());
+    assert_eq!(*found.bounds(), grandchild_bounds);
+
+    assert!(root.node_at(-1.0, -1.0).is_none());
+}
+
+#[test]
+fn layout_tree_zero_size_children_collapse_to_zero_area() {
+    let bounds = Rect::from_points(0.0, 0.0, 4.0, 4.0);
+
+    let mut root = TreemapNode::with_children(vec![
+        TreemapNode::new(0.0),
+        TreemapNode::new(0.0),
+        TreemapNode::new(0.0),
+    ]);
+
+    let layout = TreemapLayout::new();
+    layout.layout_tree(&mut root, bounds);
+
+    for child in root.children().unwrap() {
+        assert_eq!(child.bounds().w, 0.0);
+        assert_eq!(child.bounds().h, 0.0);
+    }
+}